@@ -32,12 +32,12 @@ pub(crate) struct Geo {
 pub(crate) async fn call_api(
     client: &Client,
     token: &str,
+    base_url: &str,
     postcode: &str,
     house_number: u32,
-    full: bool,
 ) -> Result<Response, PostcodeError> {
-    let url = if full { API_URL_FULL } else { API_URL_SIMPLE };
-    let url = Url::parse_with_params(url, &[("postcode", postcode), ("number", &house_number.to_string())]).unwrap();
+    let url = Url::parse_with_params(base_url, &[("postcode", postcode), ("number", &house_number.to_string())])
+        .map_err(|e| PostcodeError::NoApiResponse(format!("Invalid base URL `{base_url}`, {e}")))?;
 
     let response = client
         .get(url)
@@ -49,7 +49,9 @@ pub(crate) async fn call_api(
     match response.status() {
         StatusCode::OK => (),
         StatusCode::NOT_FOUND => (), // This is not an error, it just means the address was not found
-        StatusCode::TOO_MANY_REQUESTS => return Err(PostcodeError::TooManyRequests("API limits exceeded".to_string())),
+        // Handled by the caller so it can inspect the rate-limit headers and
+        // optionally retry.
+        StatusCode::TOO_MANY_REQUESTS => (),
         _ => {
             return Err(PostcodeError::OtherApiError(format!(
                 "Received error from API, code: {}, {}",
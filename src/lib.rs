@@ -26,17 +26,59 @@
 //! # Disclaimer
 //! I am not affiliated with the API provider and as such cannot make guarantees to the correctness of the results or the availability of the underlying service. Refer to <https://postcode.tech> for the service terms and conditions.
 
-use internals::{call_api, IntoInternal, PostcodeApiFullResponse, PostcodeApiSimpleResponse};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use futures::stream::{self, StreamExt};
+use internals::{call_api, IntoInternal, PostcodeApiFullResponse, PostcodeApiSimpleResponse, API_URL_FULL, API_URL_SIMPLE};
+use rate_limit::RateLimiter;
 use regex::Regex;
-use reqwest::{Client, StatusCode};
+use reqwest::{Client, Response, StatusCode};
 use thiserror::Error;
 
+pub use rate_limit::RateLimitMode;
+
 mod internals;
+mod rate_limit;
+
+/// Maximum number of in-flight requests for the batch lookup methods.
+const MAX_CONCURRENT_REQUESTS: usize = 16;
+
+/// Upper bound on the time a single call may spend sleeping between retries, so
+/// a call can never hang indefinitely waiting for capacity.
+const MAX_TOTAL_RETRY_WAIT: Duration = Duration::from_secs(35);
 
 /// The client that calls the API.
 pub struct PostcodeClient {
     api_token: String,
     client: Client,
+    api_url_simple: String,
+    api_url_full: String,
+    rate_limiter: Option<RateLimiter>,
+    retry_policy: Option<RetryPolicy>,
+}
+
+/// Controls whether and how a call retries after the API responds with
+/// 429 TOO MANY REQUESTS.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts, including the first one.
+    pub max_attempts: u32,
+    /// When `true`, back off until the window reported by `x-api-reset` rolls
+    /// over; otherwise fall back to exponential backoff.
+    pub honor_reset: bool,
+}
+
+impl RetryPolicy {
+    /// How long to wait before the next attempt after a 429.
+    fn retry_delay(&self, limits: &ApiLimits, attempt: u32) -> Duration {
+        if self.honor_reset {
+            if let Some(until_reset) = duration_until_reset(&limits.api_reset) {
+                return until_reset.min(MAX_TOTAL_RETRY_WAIT);
+            }
+        }
+        let backoff = Duration::from_millis(500u64.saturating_mul(1 << (attempt - 1).min(6)));
+        backoff.min(MAX_TOTAL_RETRY_WAIT)
+    }
 }
 
 /// Simple address response.
@@ -92,9 +134,60 @@ impl PostcodeClient {
         Self {
             api_token: api_token.to_string(),
             client,
+            api_url_simple: API_URL_SIMPLE.to_string(),
+            api_url_full: API_URL_FULL.to_string(),
+            rate_limiter: None,
+            retry_policy: None,
+        }
+    }
+
+    /// Start building a client with a custom [`reqwest::Client`], default
+    /// timeout or overridden base URL. See [`PostcodeClientBuilder`].
+    /// ```rust,no_run
+    /// # use std::time::Duration;
+    /// # use postcode_nl::*;
+    /// # fn main()  {
+    /// let client = PostcodeClient::builder("YOUR_API_TOKEN")
+    ///     .timeout(Duration::from_secs(5))
+    ///     .build();
+    /// # }
+    /// ```
+    pub fn builder(api_token: &str) -> PostcodeClientBuilder {
+        PostcodeClientBuilder::new(api_token)
+    }
+
+    /// Initialize a new client that throttles outgoing requests to stay within
+    /// the documented quotas (600 requests per 30 seconds and 10,000 per day)
+    /// before they are sent, so rejected calls never waste quota. See
+    /// [`RateLimitMode`] for the difference between waiting for capacity and
+    /// failing fast.
+    /// ```rust,no_run
+    /// # use postcode_nl::*;
+    /// # fn main()  {
+    /// let client = PostcodeClient::with_rate_limit("YOUR_API_TOKEN", RateLimitMode::Blocking);
+    /// # }
+    /// ```
+    pub fn with_rate_limit(api_token: &str, mode: RateLimitMode) -> Self {
+        Self {
+            rate_limiter: Some(RateLimiter::new(mode)),
+            ..Self::new(api_token)
         }
     }
 
+    /// Retry calls that are rejected with 429 TOO MANY REQUESTS according to
+    /// the given [`RetryPolicy`] instead of failing on the first rejection.
+    /// ```rust,no_run
+    /// # use postcode_nl::*;
+    /// # fn main()  {
+    /// let client = PostcodeClient::new("YOUR_API_TOKEN")
+    ///     .with_retry_policy(RetryPolicy { max_attempts: 3, honor_reset: true });
+    /// # }
+    /// ```
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
     /// Find the address matching the given postcode and house number. Postcodes are formatted 1234AB or 1234 AB (with a single space). House numbers must be integers and not include postfix characters. Returns `None` when the address could not be found.
     /// ```rust,no_run
     /// # use std::error::Error;
@@ -113,9 +206,8 @@ impl PostcodeClient {
     ) -> Result<(Option<Address>, ApiLimits), PostcodeError> {
         let postcode = Self::validate_postcode_input(postcode)?;
 
-        let response = call_api(&self.client, &self.api_token, postcode, house_number, false).await?;
+        let (response, limits) = self.execute(postcode, house_number, false).await?;
 
-        let limits = response.headers().try_into()?;
         let address = if response.status() == StatusCode::OK {
             Some(
                 response
@@ -151,9 +243,8 @@ impl PostcodeClient {
     ) -> Result<(Option<ExtendedAddress>, ApiLimits), PostcodeError> {
         let postcode = Self::validate_postcode_input(postcode)?;
 
-        let response = call_api(&self.client, &self.api_token, postcode, house_number, true).await?;
+        let (response, limits) = self.execute(postcode, house_number, true).await?;
 
-        let limits = response.headers().try_into()?;
         let address = if response.status() == StatusCode::OK {
             Some(
                 response
@@ -171,6 +262,116 @@ impl PostcodeClient {
         Ok((address, limits))
     }
 
+    /// Resolve a slice of postcode/house-number pairs concurrently, returning a
+    /// result per input in the same order. Lookups run with a bounded
+    /// concurrency of [`MAX_CONCURRENT_REQUESTS`] so a large batch does not open
+    /// hundreds of sockets at once, and each lookup respects the rate limiter
+    /// when one is configured. Invalid postcodes short-circuit into
+    /// [`PostcodeError::InvalidInput`] without spending any quota.
+    /// ```rust,no_run
+    /// # use std::error::Error;
+    /// # use postcode_nl::*;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let client: PostcodeClient = PostcodeClient::new("YOUR_API_TOKEN");
+    /// let results = client.get_addresses(&[("1012RJ", 147), ("1012RJ", 148)]).await;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_addresses(
+        &self,
+        inputs: &[(&str, u32)],
+    ) -> Vec<Result<(Option<Address>, ApiLimits), PostcodeError>> {
+        stream::iter(inputs.iter().copied())
+            .map(|(postcode, house_number)| self.get_address(postcode, house_number))
+            .buffered(MAX_CONCURRENT_REQUESTS)
+            .collect()
+            .await
+    }
+
+    /// Resolve a slice of postcode/house-number pairs concurrently, including
+    /// municipality, province and coordinates. Behaves like [`get_addresses`]
+    /// with respect to ordering, bounded concurrency, rate limiting and input
+    /// validation.
+    ///
+    /// [`get_addresses`]: PostcodeClient::get_addresses
+    /// ```rust,no_run
+    /// # use std::error::Error;
+    /// # use postcode_nl::*;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let client: PostcodeClient = PostcodeClient::new("YOUR_API_TOKEN");
+    /// let results = client.get_extended_addresses(&[("1012RJ", 147), ("1012RJ", 148)]).await;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_extended_addresses(
+        &self,
+        inputs: &[(&str, u32)],
+    ) -> Vec<Result<(Option<ExtendedAddress>, ApiLimits), PostcodeError>> {
+        stream::iter(inputs.iter().copied())
+            .map(|(postcode, house_number)| self.get_extended_address(postcode, house_number))
+            .buffered(MAX_CONCURRENT_REQUESTS)
+            .collect()
+            .await
+    }
+
+    /// Reserve rate-limiter capacity, send the request and, when a retry policy
+    /// is configured, retry on 429 TOO MANY REQUESTS until the response
+    /// succeeds or the attempt/time budget is spent. Returns the final response
+    /// together with its parsed [`ApiLimits`].
+    async fn execute(
+        &self,
+        postcode: &str,
+        house_number: u32,
+        full: bool,
+    ) -> Result<(Response, ApiLimits), PostcodeError> {
+        let max_attempts = self.retry_policy.as_ref().map(|p| p.max_attempts).unwrap_or(1).max(1);
+        let mut total_wait = Duration::ZERO;
+        let mut attempt = 0;
+
+        loop {
+            attempt += 1;
+
+            if let Some(rate_limiter) = &self.rate_limiter {
+                rate_limiter.acquire().await?;
+            }
+
+            let base_url = if full { &self.api_url_full } else { &self.api_url_simple };
+            let response = call_api(&self.client, &self.api_token, base_url, postcode, house_number).await?;
+
+            let limits: ApiLimits = response.headers().try_into()?;
+            if let Some(rate_limiter) = &self.rate_limiter {
+                rate_limiter.sync_from_limits(&limits);
+            }
+
+            if response.status() != StatusCode::TOO_MANY_REQUESTS {
+                return Ok((response, limits));
+            }
+
+            // The daily quota is exhausted; retrying before the daily reset is
+            // pointless, so fail fast instead of burning the attempt budget.
+            if limits.api_remaining == 0 {
+                return Err(PostcodeError::DailyQuotaExhausted {
+                    reset: limits.api_reset.clone(),
+                    limits: Some(limits),
+                });
+            }
+
+            match &self.retry_policy {
+                Some(policy) if attempt < max_attempts => {
+                    let wait = policy.retry_delay(&limits, attempt);
+                    if total_wait + wait > MAX_TOTAL_RETRY_WAIT {
+                        return Err(rate_limited_error(limits));
+                    }
+                    total_wait += wait;
+                    tokio::time::sleep(wait).await;
+                }
+                _ => return Err(rate_limited_error(limits)),
+            }
+        }
+    }
+
     fn validate_postcode_input(postcode: &str) -> Result<&str, PostcodeError> {
         let postcode_pattern = Regex::new(r"^\d{4} {0,1}[a-zA-Z]{2}$").unwrap();
         if postcode_pattern.is_match(postcode) {
@@ -183,6 +384,121 @@ impl PostcodeClient {
     }
 }
 
+/// Builder for a [`PostcodeClient`] with a custom [`reqwest::Client`], default
+/// request timeout, overridden base URL, rate limiting and retry policy.
+/// ```rust,no_run
+/// # use std::time::Duration;
+/// # use postcode_nl::*;
+/// # fn main()  {
+/// let client = PostcodeClient::builder("YOUR_API_TOKEN")
+///     .base_url("http://localhost:5000/api/v1/postcode")
+///     .timeout(Duration::from_secs(5))
+///     .rate_limit(RateLimitMode::Blocking)
+///     .build();
+/// # }
+/// ```
+pub struct PostcodeClientBuilder {
+    api_token: String,
+    client: Option<Client>,
+    timeout: Option<Duration>,
+    base_url: Option<String>,
+    rate_limiter: Option<RateLimiter>,
+    retry_policy: Option<RetryPolicy>,
+}
+
+impl PostcodeClientBuilder {
+    /// Start a new builder for the given API token.
+    pub fn new(api_token: &str) -> Self {
+        Self {
+            api_token: api_token.to_string(),
+            client: None,
+            timeout: None,
+            base_url: None,
+            rate_limiter: None,
+            retry_policy: None,
+        }
+    }
+
+    /// Use a pre-configured [`reqwest::Client`], e.g. one with a proxy or a
+    /// shared connection pool. When set, [`timeout`](Self::timeout) is ignored
+    /// in favour of the injected client's own configuration.
+    pub fn client(mut self, client: Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Set a default request timeout. Ignored when a custom
+    /// [`client`](Self::client) is supplied.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Override the base URL of the simple endpoint. The extended endpoint is
+    /// derived by appending `/full`, matching the live service layout.
+    pub fn base_url(mut self, base_url: &str) -> Self {
+        self.base_url = Some(base_url.to_string());
+        self
+    }
+
+    /// Throttle outgoing requests with a client-side rate limiter. See
+    /// [`RateLimitMode`].
+    pub fn rate_limit(mut self, mode: RateLimitMode) -> Self {
+        self.rate_limiter = Some(RateLimiter::new(mode));
+        self
+    }
+
+    /// Retry calls rejected with 429 according to the given [`RetryPolicy`].
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Build the [`PostcodeClient`].
+    pub fn build(self) -> PostcodeClient {
+        let client = self.client.unwrap_or_else(|| match self.timeout {
+            Some(timeout) => Client::builder().timeout(timeout).build().expect("failed to build reqwest client"),
+            None => Client::new(),
+        });
+
+        let (api_url_simple, api_url_full) = match self.base_url {
+            Some(base_url) => {
+                let full = format!("{}/full", base_url.trim_end_matches('/'));
+                (base_url, full)
+            }
+            None => (API_URL_SIMPLE.to_string(), API_URL_FULL.to_string()),
+        };
+
+        PostcodeClient {
+            api_token: self.api_token,
+            client,
+            api_url_simple,
+            api_url_full,
+            rate_limiter: self.rate_limiter,
+            retry_policy: self.retry_policy,
+        }
+    }
+}
+
+/// Build a [`PostcodeError::RateLimited`] from a 429 response's limits,
+/// deriving `retry_after` from the `x-api-reset` window when it parses.
+fn rate_limited_error(limits: ApiLimits) -> PostcodeError {
+    let retry_after = duration_until_reset(&limits.api_reset);
+    PostcodeError::RateLimited {
+        retry_after,
+        limits: Some(limits),
+    }
+}
+
+/// Interpret the `x-api-reset` header, a Unix timestamp (seconds) at which the
+/// window rolls over, as the remaining time from now. Returns `None` when the
+/// header does not parse; a reset already in the past yields [`Duration::ZERO`].
+fn duration_until_reset(api_reset: &str) -> Option<Duration> {
+    let reset = api_reset.parse::<u64>().ok()?;
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs();
+    Some(Duration::from_secs(reset.saturating_sub(now)))
+}
+
 /// Possible errors when fetching an address.
 #[derive(Debug, Error)]
 pub enum PostcodeError {
@@ -198,9 +514,22 @@ pub enum PostcodeError {
     /// The API responded that the inputs are incorrect. This should not happen and instead [`PostcodeError::InvalidInput`] should be returned.
     #[error("API returned that inputs are invalid")]
     InvalidData(String),
-    /// The API responded with 429 TOO MANY REQUESTS. You've exceeded the API limits.
-    #[error("API limits exceeded")]
-    TooManyRequests(String),
+    /// The short 30-second burst limit was hit. This is transient and the call
+    /// can be retried once the window rolls over; `retry_after` gives the wait
+    /// derived from the reset headers when it is known.
+    #[error("Rate limited, retry after the burst window rolls over")]
+    RateLimited {
+        retry_after: Option<Duration>,
+        limits: Option<ApiLimits>,
+    },
+    /// The 10,000-per-day quota is exhausted. Retrying is pointless until the
+    /// daily window resets at `reset` (the `x-api-reset` epoch timestamp, or an
+    /// empty string when the local rate limiter tripped before any response).
+    #[error("Daily quota exhausted")]
+    DailyQuotaExhausted {
+        reset: String,
+        limits: Option<ApiLimits>,
+    },
     /// The API returned an unexpected error code.
     #[error("API returned an error")]
     OtherApiError(String),
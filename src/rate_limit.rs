@@ -0,0 +1,139 @@
+use std::num::NonZeroU32;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
+
+use governor::{
+    clock::DefaultClock,
+    state::{InMemoryState, NotKeyed},
+    Quota, RateLimiter as GovernorRateLimiter,
+};
+
+use crate::{ApiLimits, PostcodeError};
+
+/// The documented burst limit: 600 requests per 30 seconds.
+const BURST_LIMIT: u32 = 600;
+const BURST_WINDOW: Duration = Duration::from_secs(30);
+/// The documented daily quota: 10,000 requests per day.
+const DAILY_LIMIT: u32 = 10_000;
+const DAILY_WINDOW: Duration = Duration::from_secs(24 * 60 * 60);
+
+type DirectRateLimiter = GovernorRateLimiter<NotKeyed, InMemoryState, DefaultClock>;
+
+/// How the client behaves when the local rate limiter has no capacity left.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitMode {
+    /// Asynchronously wait until a cell frees up before sending the request.
+    Blocking,
+    /// Return immediately when either bucket is empty:
+    /// [`PostcodeError::RateLimited`](crate::PostcodeError::RateLimited) for the
+    /// burst window, [`PostcodeError::DailyQuotaExhausted`](crate::PostcodeError::DailyQuotaExhausted)
+    /// for the daily quota.
+    NonBlocking,
+}
+
+/// Sentinel stored in [`BucketSync::last_remaining`] before the first response
+/// has been observed, so the first sync reconciles against the full quota.
+const UNSYNCED: u32 = u32::MAX;
+
+/// Per-bucket bookkeeping used to reconcile the local limiter against the
+/// server's accounting. `last_remaining` is the `remaining` value from the most
+/// recent response (or [`UNSYNCED`]); `local_consumed` counts the cells taken
+/// locally since that response so we only drain the server's *extra* usage.
+struct BucketSync {
+    last_remaining: AtomicU32,
+    local_consumed: AtomicU32,
+}
+
+impl BucketSync {
+    fn new() -> Self {
+        Self {
+            last_remaining: AtomicU32::new(UNSYNCED),
+            local_consumed: AtomicU32::new(0),
+        }
+    }
+}
+
+/// Client-side throttle that mirrors the two documented API quotas so requests
+/// are held back before they are sent instead of being rejected with a 429.
+pub(crate) struct RateLimiter {
+    burst: DirectRateLimiter,
+    burst_sync: BucketSync,
+    daily: DirectRateLimiter,
+    daily_sync: BucketSync,
+    mode: RateLimitMode,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(mode: RateLimitMode) -> Self {
+        Self {
+            burst: GovernorRateLimiter::direct(quota(BURST_WINDOW, BURST_LIMIT)),
+            burst_sync: BucketSync::new(),
+            daily: GovernorRateLimiter::direct(quota(DAILY_WINDOW, DAILY_LIMIT)),
+            daily_sync: BucketSync::new(),
+            mode,
+        }
+    }
+
+    /// Reserve a cell in both buckets before a request is sent. In
+    /// [`RateLimitMode::Blocking`] this waits for capacity, in
+    /// [`RateLimitMode::NonBlocking`] it errors as soon as a bucket is empty.
+    pub(crate) async fn acquire(&self) -> Result<(), PostcodeError> {
+        match self.mode {
+            RateLimitMode::Blocking => {
+                self.burst.until_ready().await;
+                self.daily.until_ready().await;
+            }
+            RateLimitMode::NonBlocking => {
+                self.burst.check().map_err(|_| PostcodeError::RateLimited {
+                    retry_after: None,
+                    limits: None,
+                })?;
+                // The local bucket tripped before any response, so there is no
+                // server reset to report: an empty `reset` means "unknown
+                // locally", consistent with the empty `limits`.
+                self.daily.check().map_err(|_| PostcodeError::DailyQuotaExhausted {
+                    reset: String::new(),
+                    limits: None,
+                })?;
+            }
+        }
+        self.burst_sync.local_consumed.fetch_add(1, Ordering::Relaxed);
+        self.daily_sync.local_consumed.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Self-correct the local buckets against the server's accounting. The
+    /// server is the source of truth, so we look at how many cells it consumed
+    /// since the previous response and drain only the part we have not already
+    /// accounted for locally — never the whole used-count, which would drain
+    /// more on every call. This is best-effort: it never blocks and silently
+    /// ignores a bucket that cannot satisfy the drain.
+    pub(crate) fn sync_from_limits(&self, limits: &ApiLimits) {
+        drain_delta(&self.burst, &self.burst_sync, limits.ratelimit_limit, limits.ratelimit_remaining);
+        drain_delta(&self.daily, &self.daily_sync, limits.api_limit, limits.api_remaining);
+    }
+}
+
+fn quota(window: Duration, limit: u32) -> Quota {
+    let limit = NonZeroU32::new(limit).expect("quota limit must be non-zero");
+    Quota::with_period(window / limit.get())
+        .expect("quota period must be non-zero")
+        .allow_burst(limit)
+}
+
+fn drain_delta(limiter: &DirectRateLimiter, sync: &BucketSync, limit: u32, remaining: u32) {
+    // Baseline against the full quota on the first response so pre-sync usage is
+    // reconciled exactly once; afterwards compare against the previous reading.
+    let previous = match sync.last_remaining.swap(remaining, Ordering::Relaxed) {
+        UNSYNCED => limit,
+        previous => previous,
+    };
+    let local_consumed = sync.local_consumed.swap(0, Ordering::Relaxed);
+
+    // Cells the server counted since the last sync that our own `acquire` calls
+    // have not already taken out of the local bucket.
+    let server_consumed = previous.saturating_sub(remaining);
+    if let Some(n) = NonZeroU32::new(server_consumed.saturating_sub(local_consumed)) {
+        let _ = limiter.check_n(n);
+    }
+}
@@ -0,0 +1,71 @@
+//! Integration tests for [`PostcodeClientBuilder`], exercising the overridable
+//! base URL against a local mock server instead of the live service.
+
+use httpmock::prelude::*;
+use postcode_nl::PostcodeClient;
+
+/// The rate-limit headers the client parses into `ApiLimits` on every response.
+fn with_limit_headers(then: httpmock::Then) -> httpmock::Then {
+    then.header("x-ratelimit-limit", "600")
+        .header("x-ratelimit-remaining", "599")
+        .header("x-api-limit", "10000")
+        .header("x-api-remaining", "9999")
+        .header("x-api-reset", "1700000000")
+}
+
+#[tokio::test]
+async fn builder_base_url_override_targets_the_mock_server() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/api/v1/postcode")
+            .query_param("postcode", "1012RJ")
+            .query_param("number", "147");
+        with_limit_headers(then.status(200).json_body(serde_json::json!({
+            "street": "Dam",
+            "city": "Amsterdam",
+        })));
+    });
+
+    let client = PostcodeClient::builder("TEST_TOKEN")
+        .base_url(&server.url("/api/v1/postcode"))
+        .build();
+
+    let (address, limits) = client.get_address("1012RJ", 147).await.unwrap();
+
+    mock.assert();
+    let address = address.expect("address should be present");
+    assert_eq!(address.street, "Dam");
+    assert_eq!(address.city, "Amsterdam");
+    assert_eq!(address.house_number, 147);
+    assert_eq!(address.postcode, "1012RJ");
+    assert_eq!(limits.ratelimit_remaining, 599);
+}
+
+#[tokio::test]
+async fn builder_base_url_override_derives_the_extended_endpoint() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(GET).path("/api/v1/postcode/full");
+        with_limit_headers(then.status(200).json_body(serde_json::json!({
+            "postcode": "1012RJ",
+            "number": 147,
+            "street": "Dam",
+            "city": "Amsterdam",
+            "municipality": "Amsterdam",
+            "province": "Noord-Holland",
+            "geo": { "lat": 52.37, "lon": 4.89 },
+        })));
+    });
+
+    let client = PostcodeClient::builder("TEST_TOKEN")
+        .base_url(&server.url("/api/v1/postcode"))
+        .build();
+
+    let (address, _limits) = client.get_extended_address("1012RJ", 147).await.unwrap();
+
+    mock.assert();
+    let address = address.expect("address should be present");
+    assert_eq!(address.municipality, "Amsterdam");
+    assert_eq!(address.province, "Noord-Holland");
+}